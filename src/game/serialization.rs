@@ -1,10 +1,744 @@
 use super::*;
 
 use bincode::error::{DecodeError, EncodeError};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use memmap2::Mmap;
 use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
 
+/// Version string written into every new save. Bump this whenever the on-disk
+/// layout changes and append the new value to [`READABLE_VERSIONS`].
 static VERSION_STR: &str = "2023-03-01";
 
+/// Magic marker at the very start of a framed save: ASCII `"PFS1"` in
+/// little-endian. Lets a reader reject unrelated files before touching bincode.
+const CONTAINER_MAGIC: u32 = u32::from_le_bytes(*b"PFS1");
+
+/// Container framing revision. Independent of [`VERSION_STR`], which versions
+/// the bincode payload; this versions the magic/flags/CRC envelope around it.
+const CONTAINER_VERSION: u8 = 2;
+
+/// Flag bit: the four storage arrays in the payload are DEFLATE-compressed.
+const FLAG_COMPRESSED: u8 = 0b0000_0001;
+
+/// Flag bit: the save uses the memory-mappable layout — the four storage
+/// arrays live contiguously at aligned file offsets described by the table at
+/// the front, and the bincode payload carries empty placeholders for them.
+const FLAG_MMAP: u8 = 0b0000_0010;
+
+/// Flag bit: strategies/cfvalues are bit-packed at the header's width instead
+/// of being carried in the storage arrays (which are then empty placeholders).
+const FLAG_BIT_PACKED: u8 = 0b0000_0100;
+
+/// Alignment (bytes) of each storage block in the mmap layout. Keeping blocks
+/// cache-line aligned lets nodes hold well-aligned pointers into the mapping.
+const MMAP_ALIGN: usize = 64;
+
+/// Length of the fixed container header: magic (4) + version (1) + flags (1) +
+/// bit-pack width (1) + CRC32 (4). The width byte is 0 unless
+/// [`FLAG_BIT_PACKED`] is set.
+const HEADER_LEN: usize = 4 + 1 + 1 + 1 + 4;
+
+thread_local! {
+    /// Whether the storage arrays in the current encode/decode pass are
+    /// DEFLATE-compressed. Set from the container flags before the bincode pass
+    /// runs, mirroring the base-pointer thread-locals below.
+    static STORAGE_COMPRESSED: Cell<bool> = const { Cell::new(false) };
+
+    /// While writing the mmap layout, the storage arrays are emitted as empty
+    /// placeholders in the bincode payload (their bytes live in the aligned
+    /// region instead).
+    static MMAP_WRITING: Cell<bool> = const { Cell::new(false) };
+
+    /// Base pointers into a read-only mapping, captured from [`load_mmap`]. When
+    /// set, `Decode for PostFlopGame` points nodes at these instead of at
+    /// freshly-decoded owned `Vec`s, so browsing a mapped solve is zero-copy.
+    static MMAP_BASES: Cell<Option<MmapBases>> = const { Cell::new(None) };
+
+    /// Bits per stored value when a bit-packed pass is active, or 0 for the
+    /// default storage-array representation. Set from the header's width byte
+    /// before the bincode pass runs.
+    static BIT_PACK_WIDTH: Cell<u8> = const { Cell::new(0) };
+
+    /// While decoding a bit-packed save, the running byte cursor into each of
+    /// the four owned storage arrays that nodes are filled from, in arena order:
+    /// (storage1, storage2, storage_ip, storage_chance).
+    static BIT_PACK_CURSORS: Cell<[usize; 4]> = const { Cell::new([0; 4]) };
+
+    /// Whether the game being bit-pack encoded stores its elements as i16
+    /// (compression mode), so the encoder knows how to read them back to `f32`.
+    static BIT_PACK_SRC_COMPRESSED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Base pointers into a mapped save region, one per storage array, mirroring
+/// the `ACTION_BASE`/`IP_BASE`/`CHANCE_BASE` thread-locals used during an owned
+/// decode. Nodes are reconstructed relative to these via `offset`.
+#[derive(Clone, Copy)]
+struct MmapBases {
+    action1: *mut u8,
+    action2: *mut u8,
+    ip: *mut u8,
+    chance: *mut u8,
+}
+
+fn bincode_config() -> bincode::config::Configuration {
+    bincode::config::standard()
+}
+
+/// DEFLATE-compresses `bytes`.
+fn deflate(bytes: &[u8]) -> Result<Vec<u8>, EncodeError> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(bytes)
+        .and_then(|()| encoder.finish())
+        .map_err(|e| EncodeError::OtherString(format!("compression failed: {e}")))
+}
+
+/// Inverse of [`deflate`].
+fn inflate(bytes: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut out = Vec::new();
+    DeflateDecoder::new(bytes)
+        .read_to_end(&mut out)
+        .map_err(|e| DecodeError::OtherString(format!("decompression failed: {e}")))?;
+    Ok(out)
+}
+
+/// Accumulates unsigned values at a fixed sub-byte width into a byte buffer,
+/// least-significant-bit first. [`BitWriter::into_bytes`] flushes the trailing
+/// partial byte, so a buffer always ends on a byte boundary.
+struct BitWriter {
+    width: u32,
+    bits: u64,
+    filled: u32,
+    bytes: Vec<u8>,
+}
+
+impl BitWriter {
+    fn new(width: u8) -> Self {
+        Self {
+            width: width as u32,
+            bits: 0,
+            filled: 0,
+            bytes: Vec::new(),
+        }
+    }
+
+    /// Appends `value` using the low `width` bits; higher bits are ignored.
+    fn push(&mut self, value: u32) {
+        let mask = if self.width == 32 { u32::MAX } else { (1 << self.width) - 1 };
+        self.bits |= ((value & mask) as u64) << self.filled;
+        self.filled += self.width;
+        while self.filled >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.filled -= 8;
+        }
+    }
+
+    /// Flushes any partial byte and returns the accumulated bytes.
+    fn into_bytes(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Reads back values written by [`BitWriter`] at the same width.
+struct BitReader<'a> {
+    width: u32,
+    bits: u64,
+    filled: u32,
+    bytes: std::slice::Iter<'a, u8>,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8], width: u8) -> Self {
+        Self {
+            width: width as u32,
+            bits: 0,
+            filled: 0,
+            bytes: bytes.iter(),
+        }
+    }
+
+    /// Reads the next value. Returns 0 once the backing bytes are exhausted.
+    fn next_value(&mut self) -> u32 {
+        while self.filled < self.width {
+            let byte = self.bytes.next().copied().unwrap_or(0);
+            self.bits |= (byte as u64) << self.filled;
+            self.filled += 8;
+        }
+        let mask = if self.width == 32 { u64::MAX } else { (1 << self.width) - 1 };
+        let value = (self.bits & mask) as u32;
+        self.bits >>= self.width;
+        self.filled -= self.width;
+        value
+    }
+}
+
+/// Quantizes `values` to `width` bits each. Signed arrays (cfvalues) map
+/// `[-scale, scale]` onto `[0, max]`; unsigned arrays (strategy frequencies,
+/// which are non-negative) map `[0, scale]` onto the full `[0, max]`, keeping
+/// the extra bit of precision the signed mapping would waste. A zero scale
+/// encodes all-zero values losslessly.
+fn pack_values(values: &[f32], width: u8, scale: f32, signed: bool) -> Vec<u8> {
+    let mut writer = BitWriter::new(width);
+    let max = ((1u32 << width) - 1) as f32;
+    for &value in values {
+        let q = if scale == 0.0 {
+            0.0
+        } else if signed {
+            (((value / scale) * 0.5 + 0.5) * max).round().clamp(0.0, max)
+        } else {
+            ((value / scale) * max).round().clamp(0.0, max)
+        };
+        writer.push(q as u32);
+    }
+    writer.into_bytes()
+}
+
+/// Inverse of [`pack_values`]; `signed` must match the value used to pack.
+fn unpack_values(bytes: &[u8], count: usize, width: u8, scale: f32, signed: bool) -> Vec<f32> {
+    let mut reader = BitReader::new(bytes, width);
+    let max = ((1u32 << width) - 1) as f32;
+    (0..count)
+        .map(|_| {
+            let q = reader.next_value() as f32;
+            if scale == 0.0 {
+                0.0
+            } else if signed {
+                (q / max * 2.0 - 1.0) * scale
+            } else {
+                q / max * scale
+            }
+        })
+        .collect()
+}
+
+/// Reads `count` stored values at `ptr`, dequantizing by `scale` when
+/// `compressed`: signed arrays (cfvalues) hold i16 entries, unsigned ones (the
+/// action-node strategy) hold u16 entries. Used by the bit-packed encoder to
+/// recover `f32` values from whichever storage mode the solved game used.
+fn read_storage_values(
+    ptr: *const u8,
+    count: u32,
+    scale: f32,
+    compressed: bool,
+    signed: bool,
+) -> Vec<f32> {
+    let n = count as usize;
+    if ptr.is_null() || n == 0 {
+        return Vec::new();
+    }
+    // SAFETY: the node owns `n` elements at `ptr` in the active storage mode.
+    unsafe {
+        if compressed && signed {
+            let slice = std::slice::from_raw_parts(ptr as *const i16, n);
+            let factor = scale / i16::MAX as f32;
+            slice.iter().map(|&v| v as f32 * factor).collect()
+        } else if compressed {
+            let slice = std::slice::from_raw_parts(ptr as *const u16, n);
+            let factor = scale / u16::MAX as f32;
+            slice.iter().map(|&v| v as f32 * factor).collect()
+        } else {
+            std::slice::from_raw_parts(ptr as *const f32, n).to_vec()
+        }
+    }
+}
+
+/// Encodes one storage array, DEFLATE-compressing it first when the current
+/// pass requested compression. Either way the wire type is `Vec<u8>`, so
+/// [`decode_storage`] reads it back symmetrically.
+fn encode_storage<E: bincode::enc::Encoder>(
+    storage: &MutexLike<Vec<u8>>,
+    encoder: &mut E,
+) -> Result<(), EncodeError> {
+    if MMAP_WRITING.with(|c| c.get()) || BIT_PACK_WIDTH.with(|c| c.get()) != 0 {
+        // Values live elsewhere — in the aligned region (mmap) or bit-packed in
+        // the node payloads. Emit an empty placeholder so the payload keeps the
+        // same shape the decoder expects.
+        return Vec::<u8>::new().encode(encoder);
+    }
+    let guard = storage.lock();
+    if STORAGE_COMPRESSED.with(|c| c.get()) {
+        deflate(&guard)?.encode(encoder)
+    } else {
+        guard.encode(encoder)
+    }
+}
+
+/// Inverse of [`encode_storage`]; inflates when the current pass is compressed.
+fn decode_storage<D: bincode::de::Decoder>(
+    decoder: &mut D,
+) -> Result<MutexLike<Vec<u8>>, DecodeError> {
+    let bytes = Vec::<u8>::decode(decoder)?;
+    let bytes = if STORAGE_COMPRESSED.with(|c| c.get()) {
+        inflate(&bytes)?
+    } else {
+        bytes
+    };
+    Ok(MutexLike::new(bytes))
+}
+
+/// Serializes `game` into a framed, integrity-checked byte buffer: a container
+/// header (magic, container version, flag byte, bit-pack width byte (0 here),
+/// CRC32 over the payload) followed by the bincode payload. When `compress` is set, the four storage
+/// arrays — which dominate file size, especially in i16-quantized
+/// (`is_compression_enabled`) mode — are DEFLATE-compressed inside the payload.
+pub fn encode_game(game: &PostFlopGame, compress: bool) -> Result<Vec<u8>, EncodeError> {
+    STORAGE_COMPRESSED.with(|c| c.set(compress));
+    let payload = bincode::encode_to_vec(game, bincode_config())?;
+    STORAGE_COMPRESSED.with(|c| c.set(false));
+
+    let flags = if compress { FLAG_COMPRESSED } else { 0 };
+    Ok(frame_payload(&payload, flags, 0))
+}
+
+/// Prepends the container header to a finished bincode payload.
+fn frame_payload(payload: &[u8], flags: u8, width: u8) -> Vec<u8> {
+    let crc = crc32fast::hash(payload);
+    let mut out = Vec::with_capacity(HEADER_LEN + payload.len());
+    out.extend_from_slice(&CONTAINER_MAGIC.to_le_bytes());
+    out.push(CONTAINER_VERSION);
+    out.push(flags);
+    out.push(width);
+    out.extend_from_slice(&crc.to_le_bytes());
+    out.extend_from_slice(payload);
+    out
+}
+
+/// Serializes `game` with strategies/cfvalues bit-packed at `bit_width` bits
+/// per value (8 or 12 are typical). Each action node emits its `num_elements`
+/// and `num_elements_ip` values through the bit packer using the node's own
+/// `scale1`/`scale2`/`scale3` as the dequantization ranges (chance nodes emit
+/// `storage1` with `scale1`), trading a tunable amount of
+/// precision for substantially smaller files than the fixed i16 path. The
+/// width is recorded in the header so [`decode_game`] can read it back.
+pub fn encode_game_quantized(game: &PostFlopGame, bit_width: u8) -> Result<Vec<u8>, EncodeError> {
+    if !matches!(bit_width, 1..=16) {
+        return Err(EncodeError::OtherString(format!(
+            "bit-pack width must be in 1..=16, got {bit_width}"
+        )));
+    }
+
+    // Quantization reuses each node's `scale1`/`scale2`/`scale3` as the
+    // dequantization range, and those are only populated when the game was
+    // solved in i16-compression mode. In f32 mode they are 0.0, which would map
+    // every value to zero and silently destroy the save, so refuse it.
+    if !game.is_compression_enabled {
+        return Err(EncodeError::OtherString(
+            "bit-packed storage requires a game solved in compression mode".to_string(),
+        ));
+    }
+
+    BIT_PACK_WIDTH.with(|c| c.set(bit_width));
+    BIT_PACK_SRC_COMPRESSED.with(|c| c.set(game.is_compression_enabled));
+    let payload = bincode::encode_to_vec(game, bincode_config());
+    BIT_PACK_WIDTH.with(|c| c.set(0));
+
+    Ok(frame_payload(&payload?, FLAG_BIT_PACKED, bit_width))
+}
+
+/// Inverse of [`encode_game`]. Validates the magic and container version, then
+/// verifies the CRC32 over the payload *before* decoding — so a single flipped
+/// byte is caught cleanly instead of corrupting reconstructed node pointers.
+pub fn decode_game(bytes: &[u8]) -> Result<PostFlopGame, DecodeError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(DecodeError::OtherString(
+            "file is too short to contain a container header".to_string(),
+        ));
+    }
+
+    let magic = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+    if magic != CONTAINER_MAGIC {
+        return Err(DecodeError::OtherString(
+            "bad magic marker: not a postflop-solver save".to_string(),
+        ));
+    }
+
+    let container_version = bytes[4];
+    if container_version != CONTAINER_VERSION {
+        return Err(DecodeError::OtherString(format!(
+            "unsupported container version {container_version} (this build writes {CONTAINER_VERSION})"
+        )));
+    }
+
+    let flags = bytes[5];
+    let width = bytes[6];
+    if flags & FLAG_MMAP != 0 {
+        return Err(DecodeError::OtherString(
+            "file uses the memory-mappable layout; use load_mmap instead".to_string(),
+        ));
+    }
+    let stored_crc = u32::from_le_bytes(bytes[7..HEADER_LEN].try_into().unwrap());
+    let payload = &bytes[HEADER_LEN..];
+
+    let actual_crc = crc32fast::hash(payload);
+    if actual_crc != stored_crc {
+        return Err(DecodeError::OtherString(format!(
+            "checksum mismatch: file is corrupted (expected {stored_crc:#010x}, got {actual_crc:#010x})"
+        )));
+    }
+
+    STORAGE_COMPRESSED.with(|c| c.set(flags & FLAG_COMPRESSED != 0));
+    BIT_PACK_WIDTH.with(|c| c.set(if flags & FLAG_BIT_PACKED != 0 { width } else { 0 }));
+    BIT_PACK_CURSORS.with(|c| c.set([0; 4]));
+    let result = bincode::decode_from_slice(payload, bincode_config()).map(|(game, _)| game);
+    STORAGE_COMPRESSED.with(|c| c.set(false));
+    BIT_PACK_WIDTH.with(|c| c.set(0));
+    result
+}
+
+/// Rounds `offset` up to the next multiple of [`MMAP_ALIGN`].
+#[inline]
+fn align_up(offset: usize) -> usize {
+    (offset + MMAP_ALIGN - 1) & !(MMAP_ALIGN - 1)
+}
+
+/// A game mapped read-only from disk. Nodes point directly into the mapping, so
+/// construction touches no heap proportional to the storage size. The mapping
+/// is kept alive for as long as the game is borrowed; mutating operations
+/// require [`MmapGame::into_owned`], which copies the storage out first.
+pub struct MmapGame {
+    // Dropped first (fields drop in declaration order): the game holds raw
+    // pointers into `mmap`, which is dropped after it.
+    game: PostFlopGame,
+    mmap: Mmap,
+    // Base pointers and byte lengths of the four storage blocks inside the
+    // mapping, captured at load time so `into_owned` can copy them out and
+    // rebase the node pointers without re-reading the file.
+    bases: MmapBases,
+    block_lens: [usize; 4],
+}
+
+impl MmapGame {
+    /// The mapped game. Read-only browsing (strategies, EVs, tree walking) is
+    /// safe; do not call solving or updating methods on it — use
+    /// [`MmapGame::into_owned`] for that.
+    #[inline]
+    pub fn game(&self) -> &PostFlopGame {
+        &self.game
+    }
+
+    /// Copy-on-open: materializes an owned [`PostFlopGame`] whose storage lives
+    /// on the heap, after which the mapping can be dropped and the game mutated
+    /// (solved, updated) freely. The four storage blocks are `memcpy`'d out of
+    /// the mapping into owned `Vec`s and every node pointer is rebased from the
+    /// mapped block onto its owned copy.
+    pub fn into_owned(self) -> PostFlopGame {
+        let game = self.game;
+        let bases = self.bases;
+
+        // Copy each mapped block into an owned buffer.
+        let copy = |ptr: *mut u8, len: usize| -> Vec<u8> {
+            if ptr.is_null() || len == 0 {
+                Vec::new()
+            } else {
+                // SAFETY: `ptr`/`len` describe a block inside the still-live
+                // mapping, bounds-checked when the mapping was opened.
+                unsafe { std::slice::from_raw_parts(ptr, len) }.to_vec()
+            }
+        };
+        let mut storage1 = copy(bases.action1, self.block_lens[0]);
+        let mut storage2 = copy(bases.action2, self.block_lens[1]);
+        let mut storage_ip = copy(bases.ip, self.block_lens[2]);
+        let mut storage_chance = copy(bases.chance, self.block_lens[3]);
+
+        // New base pointers; a `Vec` move preserves its heap allocation, so
+        // these stay valid after the buffers are stored on the game below.
+        let new = MmapBases {
+            action1: storage1.as_mut_ptr(),
+            action2: storage2.as_mut_ptr(),
+            ip: storage_ip.as_mut_ptr(),
+            chance: storage_chance.as_mut_ptr(),
+        };
+
+        // Rebase every node pointer from the mapping onto the owned buffers.
+        let rebase = |ptr: *mut u8, old: *mut u8, new: *mut u8| -> *mut u8 {
+            if ptr.is_null() {
+                ptr
+            } else {
+                // SAFETY: `ptr` points inside the `old` block; the same offset
+                // is valid in the equally-sized `new` buffer.
+                unsafe { new.offset(ptr.offset_from(old)) }
+            }
+        };
+        for node in game.node_arena.iter() {
+            let mut node = node.lock();
+            if node.is_terminal() || node.storage1.is_null() {
+                continue;
+            }
+            if node.is_chance() {
+                node.storage1 = rebase(node.storage1, bases.chance, new.chance);
+            } else {
+                node.storage1 = rebase(node.storage1, bases.action1, new.action1);
+                node.storage2 = rebase(node.storage2, bases.action2, new.action2);
+                node.storage3 = rebase(node.storage3, bases.ip, new.ip);
+            }
+        }
+
+        *game.storage1.lock() = storage1;
+        *game.storage2.lock() = storage2;
+        *game.storage_ip.lock() = storage_ip;
+        *game.storage_chance.lock() = storage_chance;
+
+        drop(self.mmap);
+        game
+    }
+}
+
+/// Serializes `game` into the memory-mappable layout: a container header, a
+/// table of `(offset, length)` for each of the four storage arrays, the arrays
+/// themselves written contiguously at [`MMAP_ALIGN`]-aligned offsets, and
+/// finally the bincode payload (with empty storage placeholders). The CRC32 in
+/// the header covers the bincode payload, as in [`encode_game`].
+///
+/// Integrity limitation: unlike [`encode_game`], the CRC does **not** cover the
+/// four storage arrays. Checksumming them would require reading the entire file
+/// at open time, which defeats the point of the memory-mappable layout (opening
+/// a multi-gigabyte solve without a resident copy). Corruption inside the
+/// storage region is therefore not detected by [`load_mmap`]; callers that need
+/// that guarantee should use [`encode_game`]/[`decode_game`] instead.
+pub fn encode_game_mmap(game: &PostFlopGame) -> Result<Vec<u8>, EncodeError> {
+    MMAP_WRITING.with(|c| c.set(true));
+    let payload = bincode::encode_to_vec(game, bincode_config());
+    MMAP_WRITING.with(|c| c.set(false));
+    let payload = payload?;
+
+    let arrays = [
+        game.storage1.lock(),
+        game.storage2.lock(),
+        game.storage_ip.lock(),
+        game.storage_chance.lock(),
+    ];
+
+    // Lay out the blocks after the header and the 4-entry table to compute
+    // their aligned file offsets.
+    let table_len = 4 * 2 * std::mem::size_of::<u64>();
+    let mut cursor = align_up(HEADER_LEN + table_len);
+    let mut table = [(0u64, 0u64); 4];
+    for (entry, array) in table.iter_mut().zip(arrays.iter()) {
+        *entry = (cursor as u64, array.len() as u64);
+        cursor = align_up(cursor + array.len());
+    }
+
+    // The payload is written at the aligned `cursor` offset but its tail is NOT
+    // padded: `load_mmap` re-hashes `&mmap[payload_start..]`, so any trailing
+    // pad bytes would land inside the CRC range and fail the integrity check.
+    let total = cursor + payload.len();
+    let mut out = vec![0u8; total];
+
+    out[0..4].copy_from_slice(&CONTAINER_MAGIC.to_le_bytes());
+    out[4] = CONTAINER_VERSION;
+    out[5] = FLAG_MMAP;
+    out[6] = 0; // width: bit-packing is not combined with the mmap layout
+    out[7..HEADER_LEN].copy_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+
+    let mut pos = HEADER_LEN;
+    for (offset, len) in table {
+        out[pos..pos + 8].copy_from_slice(&offset.to_le_bytes());
+        out[pos + 8..pos + 16].copy_from_slice(&len.to_le_bytes());
+        pos += 16;
+    }
+
+    for (entry, array) in table.iter().zip(arrays.iter()) {
+        let start = entry.0 as usize;
+        out[start..start + array.len()].copy_from_slice(array);
+    }
+
+    out[cursor..cursor + payload.len()].copy_from_slice(&payload);
+    Ok(out)
+}
+
+/// Maps the save at `path` read-only and reconstructs a [`PostFlopGame`] whose
+/// nodes point directly into the mapping, so opening a multi-gigabyte solve
+/// needs no up-front resident copy of the storage arrays. Only the mmap layout
+/// written by [`encode_game_mmap`] is accepted. The returned [`MmapGame`] keeps
+/// the mapping alive and exposes the game for read-only browsing.
+///
+/// The header CRC is verified over the bincode payload only; as noted on
+/// [`encode_game_mmap`], the storage arrays are not checksummed, so corruption
+/// inside them is not detected here.
+pub fn load_mmap(path: impl AsRef<Path>) -> Result<MmapGame, DecodeError> {
+    let file = File::open(path)
+        .map_err(|e| DecodeError::OtherString(format!("cannot open file: {e}")))?;
+    // SAFETY: the mapping is held for the lifetime of the returned `MmapGame`
+    // and only ever read; nodes borrow into it immutably.
+    let mmap = unsafe { Mmap::map(&file) }
+        .map_err(|e| DecodeError::OtherString(format!("cannot map file: {e}")))?;
+
+    let table_len = 4 * 2 * std::mem::size_of::<u64>();
+    if mmap.len() < HEADER_LEN + table_len {
+        return Err(DecodeError::OtherString(
+            "file is too short to contain a mmap header".to_string(),
+        ));
+    }
+
+    let magic = u32::from_le_bytes(mmap[0..4].try_into().unwrap());
+    if magic != CONTAINER_MAGIC {
+        return Err(DecodeError::OtherString(
+            "bad magic marker: not a postflop-solver save".to_string(),
+        ));
+    }
+    if mmap[4] != CONTAINER_VERSION {
+        return Err(DecodeError::OtherString(format!(
+            "unsupported container version {} (this build writes {CONTAINER_VERSION})",
+            mmap[4]
+        )));
+    }
+    let flags = mmap[5];
+    if flags & FLAG_MMAP == 0 {
+        return Err(DecodeError::OtherString(
+            "file does not use the memory-mappable layout; use decode_game instead".to_string(),
+        ));
+    }
+
+    // Storage table and aligned payload offset.
+    let base = mmap.as_ptr() as *mut u8;
+    let mut table = [(0usize, 0usize); 4];
+    let mut pos = HEADER_LEN;
+    let mut max_end = HEADER_LEN + table_len;
+    for entry in table.iter_mut() {
+        let offset = u64::from_le_bytes(mmap[pos..pos + 8].try_into().unwrap()) as usize;
+        let len = u64::from_le_bytes(mmap[pos + 8..pos + 16].try_into().unwrap()) as usize;
+        *entry = (offset, len);
+        max_end = max_end.max(offset + len);
+        pos += 16;
+    }
+    let payload_start = align_up(max_end);
+
+    if payload_start > mmap.len() || table.iter().any(|&(o, l)| o + l > mmap.len()) {
+        return Err(DecodeError::OtherString(
+            "mmap table points past end of file".to_string(),
+        ));
+    }
+
+    let payload = &mmap[payload_start..];
+    let stored_crc = u32::from_le_bytes(mmap[7..HEADER_LEN].try_into().unwrap());
+    let actual_crc = crc32fast::hash(payload);
+    if actual_crc != stored_crc {
+        return Err(DecodeError::OtherString(format!(
+            "checksum mismatch: file is corrupted (expected {stored_crc:#010x}, got {actual_crc:#010x})"
+        )));
+    }
+
+    // Point the bases at the mapped blocks; `Decode` picks them up instead of
+    // capturing from owned Vecs.
+    // SAFETY: offsets are bounds-checked above and the mapping outlives the game.
+    let bases = MmapBases {
+        action1: unsafe { base.add(table[0].0) },
+        action2: unsafe { base.add(table[1].0) },
+        ip: unsafe { base.add(table[2].0) },
+        chance: unsafe { base.add(table[3].0) },
+    };
+
+    MMAP_BASES.with(|c| c.set(Some(bases)));
+    STORAGE_COMPRESSED.with(|c| c.set(false));
+    let result = bincode::decode_from_slice(payload, bincode_config()).map(|(game, _)| game);
+    MMAP_BASES.with(|c| c.set(None));
+
+    let game = result?;
+    let block_lens = [table[0].1, table[1].1, table[2].1, table[3].1];
+    Ok(MmapGame {
+        game,
+        mmap,
+        bases,
+        block_lens,
+    })
+}
+
+/// Every file format version this build can read, oldest first. The last entry
+/// is always [`VERSION_STR`]; decoding a tag not in this list is an error.
+static READABLE_VERSIONS: &[&str] = &["2023-03-01"];
+
+/// Returns the file format versions that [`PostFlopGame`] can deserialize,
+/// oldest first. Saves written by any of these versions are migrated forward to
+/// the current layout on load; the newest entry equals the version new saves
+/// are written with.
+#[inline]
+pub fn readable_versions() -> &'static [&'static str] {
+    READABLE_VERSIONS
+}
+
+/// Serialized game body, decoded but not yet reconstructed into a
+/// [`PostFlopGame`]. Each readable version decodes into this intermediate form;
+/// a chain of migration functions then upgrades it field-by-field until it
+/// matches the layout the current build expects, filling defaults for members
+/// (such as `locking_strategy`) that did not exist in older files.
+struct GameBody {
+    state: State,
+    card_config: CardConfig,
+    num_combinations: f64,
+    is_compression_enabled: bool,
+    num_storage_actions: u64,
+    num_storage_chances: u64,
+    misc_memory_usage: u64,
+    storage1: MutexLike<Vec<u8>>,
+    storage2: MutexLike<Vec<u8>>,
+    storage_ip: MutexLike<Vec<u8>>,
+    storage_chance: MutexLike<Vec<u8>>,
+    locking_strategy: BTreeMap<usize, Vec<f32>>,
+    history: Vec<usize>,
+    is_normalized_weight_cached: bool,
+}
+
+/// Decodes the body of a save produced by version `"2023-03-01"`. This is the
+/// current layout, so it reads every field directly.
+fn decode_body_2023_03_01<D: bincode::de::Decoder>(
+    decoder: &mut D,
+) -> Result<GameBody, DecodeError> {
+    Ok(GameBody {
+        state: Decode::decode(decoder)?,
+        card_config: Decode::decode(decoder)?,
+        num_combinations: Decode::decode(decoder)?,
+        is_compression_enabled: Decode::decode(decoder)?,
+        num_storage_actions: Decode::decode(decoder)?,
+        num_storage_chances: Decode::decode(decoder)?,
+        misc_memory_usage: Decode::decode(decoder)?,
+        storage1: decode_storage(decoder)?,
+        storage2: decode_storage(decoder)?,
+        storage_ip: decode_storage(decoder)?,
+        storage_chance: decode_storage(decoder)?,
+        locking_strategy: Decode::decode(decoder)?,
+        history: Decode::decode(decoder)?,
+        is_normalized_weight_cached: Decode::decode(decoder)?,
+    })
+}
+
+/// Dispatches to the decode routine registered for `version_index` (an index
+/// into [`READABLE_VERSIONS`]), then runs every migration hop from that version
+/// up to the current one. Migration `i` upgrades a body decoded at
+/// `READABLE_VERSIONS[i]` into the `READABLE_VERSIONS[i + 1]` layout; the
+/// current version needs none, so the chain is empty when `version_index`
+/// points at the last entry.
+fn decode_body<D: bincode::de::Decoder>(
+    decoder: &mut D,
+    version_index: usize,
+) -> Result<GameBody, DecodeError> {
+    let mut body = match version_index {
+        0 => decode_body_2023_03_01(decoder)?,
+        // New versions prepend their decode routine here and append a migration
+        // hop to `MIGRATIONS`.
+        _ => unreachable!("version_index is bounded by READABLE_VERSIONS"),
+    };
+    for migrate in &MIGRATIONS[version_index..] {
+        migrate(&mut body);
+    }
+    Ok(body)
+}
+
+/// Migration hops, one per gap between consecutive [`READABLE_VERSIONS`]. Hop
+/// `i` brings a [`GameBody`] from `READABLE_VERSIONS[i]` to
+/// `READABLE_VERSIONS[i + 1]`, so this list always has one fewer entry than
+/// [`READABLE_VERSIONS`].
+static MIGRATIONS: &[fn(&mut GameBody)] = &[];
+
 thread_local! {
     static ACTION_BASE: Cell<(*mut u8, *mut u8)> = Cell::new((ptr::null_mut(), ptr::null_mut()));
     static IP_BASE: Cell<*mut u8> = Cell::new(ptr::null_mut());
@@ -54,14 +788,27 @@ impl Encode for PostFlopGame {
         self.num_storage_actions.encode(encoder)?;
         self.num_storage_chances.encode(encoder)?;
         self.misc_memory_usage.encode(encoder)?;
-        self.storage1.encode(encoder)?;
-        self.storage2.encode(encoder)?;
-        self.storage_ip.encode(encoder)?;
-        self.storage_chance.encode(encoder)?;
+        encode_storage(&self.storage1, encoder)?;
+        encode_storage(&self.storage2, encoder)?;
+        encode_storage(&self.storage_ip, encoder)?;
+        encode_storage(&self.storage_chance, encoder)?;
         self.locking_strategy.encode(encoder)?;
         self.history.encode(encoder)?;
         self.is_normalized_weight_cached.encode(encoder)?;
 
+        // A bit-packed save carries empty storage placeholders, so the decoder
+        // cannot recover the IP storage element total (which is independent of
+        // `num_storage_actions`) from the storage arrays. Persist it here so the
+        // decoder can size its owned `storage_ip` buffer exactly.
+        if BIT_PACK_WIDTH.with(|c| c.get()) != 0 && self.state >= State::MemoryAllocated {
+            let num_elements_ip: u64 = self
+                .node_arena
+                .iter()
+                .map(|node| node.lock().num_elements_ip as u64)
+                .sum();
+            num_elements_ip.encode(encoder)?;
+        }
+
         // game tree
         self.node_arena.encode(encoder)?;
 
@@ -71,13 +818,30 @@ impl Encode for PostFlopGame {
 
 impl Decode for PostFlopGame {
     fn decode<D: bincode::de::Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
-        // version check
+        // version dispatch: locate the tag in the registry of readable versions
+        // and report whether an unknown tag is too old or too new.
         let version = String::decode(decoder)?;
-        if version != VERSION_STR {
-            return Err(DecodeError::OtherString(format!(
-                "Version mismatch: expected '{VERSION_STR}', but got '{version}'"
-            )));
-        }
+        let version_index = match READABLE_VERSIONS.iter().position(|&v| v == version) {
+            Some(index) => index,
+            None => {
+                // Tags are ISO-8601 dates (see `VERSION_STR`), so lexical order
+                // is chronological: a tag that sorts before the oldest readable
+                // entry predates every format this build knows, and anything else
+                // is from the future. A non-date future tag would need a richer
+                // comparison, but none is emitted by any released version.
+                let oldest = READABLE_VERSIONS[0];
+                let message = if version.as_str() < oldest {
+                    format!(
+                        "File version '{version}' is older than the oldest readable version '{oldest}'"
+                    )
+                } else {
+                    format!(
+                        "File version '{version}' is newer than this build's version '{VERSION_STR}'"
+                    )
+                };
+                return Err(DecodeError::OtherString(message));
+            }
+        };
 
         let tree_config = TreeConfig::decode(decoder)?;
         let added_lines = Vec::<Vec<Action>>::decode(decoder)?;
@@ -93,54 +857,87 @@ impl Decode for PostFlopGame {
 
         let (tree_config, _, _, action_root) = action_tree.eject();
 
+        // version-specific body, migrated forward to the current layout
+        let body = decode_body(decoder, version_index)?;
+        let history = body.history;
+        let is_normalized_weight_cached = body.is_normalized_weight_cached;
+
         // game instance
         let mut game = Self {
-            state: Decode::decode(decoder)?,
-            card_config: Decode::decode(decoder)?,
+            state: body.state,
+            card_config: body.card_config,
             tree_config,
             added_lines,
             removed_lines,
             action_root,
-            num_combinations: Decode::decode(decoder)?,
-            is_compression_enabled: Decode::decode(decoder)?,
-            num_storage_actions: Decode::decode(decoder)?,
-            num_storage_chances: Decode::decode(decoder)?,
-            misc_memory_usage: Decode::decode(decoder)?,
-            storage1: Decode::decode(decoder)?,
-            storage2: Decode::decode(decoder)?,
-            storage_ip: Decode::decode(decoder)?,
-            storage_chance: Decode::decode(decoder)?,
-            locking_strategy: Decode::decode(decoder)?,
+            num_combinations: body.num_combinations,
+            is_compression_enabled: body.is_compression_enabled,
+            num_storage_actions: body.num_storage_actions,
+            num_storage_chances: body.num_storage_chances,
+            misc_memory_usage: body.misc_memory_usage,
+            storage1: body.storage1,
+            storage2: body.storage2,
+            storage_ip: body.storage_ip,
+            storage_chance: body.storage_chance,
+            locking_strategy: body.locking_strategy,
             ..Default::default()
         };
 
-        let history = Vec::<usize>::decode(decoder)?;
-        let is_normalized_weight_cached = bool::decode(decoder)?;
+        // A bit-packed save carries empty storage placeholders; the node values
+        // are unpacked back into owned `f32` arrays as the arena is decoded, so
+        // pre-allocate them here (large enough that the per-node writes never
+        // reallocate) and load as uncompressed.
+        if BIT_PACK_WIDTH.with(|c| c.get()) != 0 && game.state >= State::MemoryAllocated {
+            // IP storage element total, persisted by the encoder: the IP arrays
+            // hold independent per-node totals, so sizing them from
+            // `num_storage_actions` would under-allocate and let the per-node
+            // writes in `decode_bit_packed` run past the buffer.
+            let num_elements_ip = u64::decode(decoder)? as usize;
+            let elem = std::mem::size_of::<f32>();
+            let actions = game.num_storage_actions as usize * elem;
+            let ip = num_elements_ip * elem;
+            let chances = game.num_storage_chances as usize * elem;
+            game.is_compression_enabled = false;
+            *game.storage1.lock() = vec![0; actions];
+            *game.storage2.lock() = vec![0; actions];
+            *game.storage_ip.lock() = vec![0; ip];
+            *game.storage_chance.lock() = vec![0; chances];
+            BIT_PACK_CURSORS.with(|c| c.set([0; 4]));
+        }
 
-        // store base pointers
+        // store base pointers. When loaded via `load_mmap`, the bases point
+        // inside the read-only mapping so nodes reference it directly; otherwise
+        // they are captured from the freshly-decoded owned storage Vecs.
+        let mmap_bases = MMAP_BASES.with(|c| c.get());
         ACTION_BASE.with(|c| {
-            if game.state >= State::MemoryAllocated {
+            if game.state < State::MemoryAllocated {
+                c.set((ptr::null_mut(), ptr::null_mut()));
+            } else if let Some(bases) = mmap_bases {
+                c.set((bases.action1, bases.action2));
+            } else {
                 let base1 = game.storage1.lock().as_mut_ptr();
                 let base2 = game.storage2.lock().as_mut_ptr();
                 c.set((base1, base2));
-            } else {
-                c.set((ptr::null_mut(), ptr::null_mut()));
             }
         });
 
         IP_BASE.with(|c| {
-            if game.state >= State::MemoryAllocated {
-                c.set(game.storage_ip.lock().as_mut_ptr());
-            } else {
+            if game.state < State::MemoryAllocated {
                 c.set(ptr::null_mut());
+            } else if let Some(bases) = mmap_bases {
+                c.set(bases.ip);
+            } else {
+                c.set(game.storage_ip.lock().as_mut_ptr());
             }
         });
 
         CHANCE_BASE.with(|c| {
-            if game.state >= State::MemoryAllocated {
-                c.set(game.storage_chance.lock().as_mut_ptr());
-            } else {
+            if game.state < State::MemoryAllocated {
                 c.set(ptr::null_mut());
+            } else if let Some(bases) = mmap_bases {
+                c.set(bases.chance);
+            } else {
+                c.set(game.storage_chance.lock().as_mut_ptr());
             }
         });
 
@@ -180,8 +977,12 @@ impl Encode for PostFlopNode {
         self.scale2.encode(encoder)?;
         self.scale3.encode(encoder)?;
 
-        // pointer offset
-        if !self.storage1.is_null() {
+        // value payload
+        let width = BIT_PACK_WIDTH.with(|c| c.get());
+        if width != 0 {
+            self.encode_bit_packed(encoder, width)?;
+        } else if !self.storage1.is_null() {
+            // pointer offset
             if self.is_terminal() {
                 // do nothing
             } else if self.is_chance() {
@@ -199,6 +1000,37 @@ impl Encode for PostFlopNode {
     }
 }
 
+impl PostFlopNode {
+    /// Emits this node's stored values bit-packed at `width` bits each, reading
+    /// from the live storage arrays via [`read_storage_values`]. Action nodes
+    /// emit `storage1`/`storage2` (`num_elements` each) and `storage3`
+    /// (`num_elements_ip`); chance nodes emit `storage1` (`num_elements`).
+    fn encode_bit_packed<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+        width: u8,
+    ) -> Result<(), EncodeError> {
+        if self.storage1.is_null() || self.is_terminal() {
+            return Ok(());
+        }
+        let compressed = BIT_PACK_SRC_COMPRESSED.with(|c| c.get());
+        let emit = |ptr, count, scale, signed, encoder: &mut E| -> Result<(), EncodeError> {
+            let values = read_storage_values(ptr, count, scale, compressed, signed);
+            pack_values(&values, width, scale, signed).encode(encoder)
+        };
+        // `storage1` at an action node is the (non-negative) strategy, so it
+        // packs unsigned; cfvalue arrays are signed.
+        if self.is_chance() {
+            emit(self.storage1, self.num_elements, self.scale1, true, encoder)?;
+        } else {
+            emit(self.storage1, self.num_elements, self.scale1, false, encoder)?;
+            emit(self.storage2, self.num_elements, self.scale2, true, encoder)?;
+            emit(self.storage3, self.num_elements_ip, self.scale3, true, encoder)?;
+        }
+        Ok(())
+    }
+}
+
 impl Decode for PostFlopNode {
     fn decode<D: bincode::de::Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
         // node instance
@@ -220,7 +1052,10 @@ impl Decode for PostFlopNode {
         };
 
         // pointers
-        if node.is_terminal() {
+        let width = BIT_PACK_WIDTH.with(|c| c.get());
+        if width != 0 {
+            node.decode_bit_packed(decoder, width)?;
+        } else if node.is_terminal() {
             // do nothing
         } else if node.is_chance() {
             let base = CHANCE_BASE.with(|c| c.get());
@@ -241,4 +1076,557 @@ impl Decode for PostFlopNode {
 
         Ok(node)
     }
-}
\ No newline at end of file
+}
+
+impl PostFlopNode {
+    /// Reads this node's bit-packed values (see [`PostFlopNode::encode_bit_packed`]),
+    /// rescales them to `f32`, and writes them contiguously into the owned
+    /// storage arrays at the running per-array cursors, pointing the node's
+    /// storage fields at the start of its slice. The arrays are pre-allocated by
+    /// `Decode for PostFlopGame`, so these writes never reallocate.
+    fn decode_bit_packed<D: bincode::de::Decoder>(
+        &mut self,
+        decoder: &mut D,
+        width: u8,
+    ) -> Result<(), DecodeError> {
+        if self.is_terminal() {
+            return Ok(());
+        }
+
+        let mut cursors = BIT_PACK_CURSORS.with(|c| c.get());
+        let mut place = |base: *mut u8, slot: usize, count: u32, scale: f32, signed: bool, decoder: &mut D| -> Result<*mut u8, DecodeError> {
+            let bytes = Vec::<u8>::decode(decoder)?;
+            if base.is_null() {
+                return Ok(ptr::null_mut());
+            }
+            let values = unpack_values(&bytes, count as usize, width, scale, signed);
+            // SAFETY: the array was allocated large enough for every node's
+            // values in arena order; `cursors[slot]` never passes its end.
+            let dst = unsafe { base.add(cursors[slot]) };
+            unsafe {
+                std::slice::from_raw_parts_mut(dst as *mut f32, values.len())
+                    .copy_from_slice(&values);
+            }
+            cursors[slot] += values.len() * std::mem::size_of::<f32>();
+            Ok(dst)
+        };
+
+        // Signedness must mirror `encode_bit_packed`: the strategy array
+        // (action `storage1`) is unsigned, every cfvalue array is signed.
+        if self.is_chance() {
+            let base = CHANCE_BASE.with(|c| c.get());
+            self.storage1 = place(base, 3, self.num_elements, self.scale1, true, decoder)?;
+        } else {
+            let (base1, base2) = ACTION_BASE.with(|c| c.get());
+            let base3 = IP_BASE.with(|c| c.get());
+            self.storage1 = place(base1, 0, self.num_elements, self.scale1, false, decoder)?;
+            self.storage2 = place(base2, 1, self.num_elements, self.scale2, true, decoder)?;
+            self.storage3 = place(base3, 2, self.num_elements_ip, self.scale3, true, decoder)?;
+        }
+
+        BIT_PACK_CURSORS.with(|c| c.set(cursors));
+        Ok(())
+    }
+}
+/// Text interchange format
+/// =======================
+///
+/// The binary encoder above is compact but tied to this crate's exact memory
+/// layout, so no external tool can read a solve. The functions below provide a
+/// human-readable alternative inspired by game-record formats like SGF: a
+/// header carrying the [`TreeConfig`] and the `added_lines`/`removed_lines`
+/// that define the action tree, followed by a parenthesised node tree whose
+/// nodes are annotated with properties (`PL` player, `PA` previous action, `TU`
+/// turn / `RI` river cards, `AM` amount, and at action nodes the per-hand
+/// strategy `ST` and cfvalue `EV` arrays reconstructed from `storage1`/
+/// `storage2`). Because [`Decode`] already rebuilds the action tree purely from
+/// `tree_config` + added/removed lines, parsing those back is enough to
+/// reconstruct and re-solve a tree independent of the binary format version.
+/// The `ST`/`EV` node annotations are emitted for external consumers (they make
+/// the export a complete record of the solve); [`import_tree_text`] does not
+/// parse them back, so a re-imported tree must be re-solved to repopulate
+/// frequencies.
+
+/// The action tree reconstructed from a text export. Feed `tree_config` plus
+/// the added/removed lines into [`ActionTree`] (exactly as [`Decode`] does) to
+/// rebuild the tree, then re-solve it (the exported frequencies are not parsed
+/// back by [`import_tree_text`]).
+pub struct ImportedTree {
+    pub tree_config: TreeConfig,
+    pub added_lines: Vec<Vec<Action>>,
+    pub removed_lines: Vec<Vec<Action>>,
+}
+
+/// Textual form of a single [`Action`], chosen to be compact and unambiguous.
+fn action_to_text(action: &Action) -> String {
+    match action {
+        Action::None => "none".to_string(),
+        Action::Fold => "F".to_string(),
+        Action::Check => "X".to_string(),
+        Action::Call => "C".to_string(),
+        Action::Bet(amount) => format!("B{amount}"),
+        Action::Raise(amount) => format!("R{amount}"),
+        Action::AllIn(amount) => format!("A{amount}"),
+        Action::Chance(card) => format!("D{card}"),
+    }
+}
+
+/// Inverse of [`action_to_text`].
+fn action_from_text(text: &str) -> Result<Action, String> {
+    let (tag, rest) = text.split_at(text.find(|c: char| c.is_ascii_digit()).unwrap_or(text.len()));
+    let parse = |s: &str| s.parse().map_err(|_| format!("bad action operand: '{text}'"));
+    match tag {
+        "none" => Ok(Action::None),
+        "F" => Ok(Action::Fold),
+        "X" => Ok(Action::Check),
+        "C" => Ok(Action::Call),
+        "B" => Ok(Action::Bet(parse(rest)?)),
+        "R" => Ok(Action::Raise(parse(rest)?)),
+        "A" => Ok(Action::AllIn(parse(rest)?)),
+        "D" => Ok(Action::Chance(parse(rest)?)),
+        _ => Err(format!("unknown action: '{text}'")),
+    }
+}
+
+/// Textual form of a single [`BetSize`].
+fn bet_size_to_text(size: &BetSize) -> String {
+    match size {
+        BetSize::PotRelative(ratio) => format!("p{ratio}"),
+        BetSize::PrevBetRelative(ratio) => format!("x{ratio}"),
+        BetSize::Additive(add, raise) => format!("d{add}_{raise}"),
+        BetSize::Geometric(n, cap) => format!("g{n}_{cap}"),
+        BetSize::AllIn => "a".to_string(),
+    }
+}
+
+/// Inverse of [`bet_size_to_text`].
+fn bet_size_from_text(text: &str) -> Result<BetSize, String> {
+    let err = || format!("bad bet size: '{text}'");
+    if text.is_empty() {
+        return Err(err());
+    }
+    let (tag, rest) = text.split_at(1);
+    let pair = || -> Result<(&str, &str), String> { rest.split_once('_').ok_or_else(err) };
+    match tag {
+        "p" => Ok(BetSize::PotRelative(rest.parse().map_err(|_| err())?)),
+        "x" => Ok(BetSize::PrevBetRelative(rest.parse().map_err(|_| err())?)),
+        "d" => {
+            let (a, b) = pair()?;
+            Ok(BetSize::Additive(
+                a.parse().map_err(|_| err())?,
+                b.parse().map_err(|_| err())?,
+            ))
+        }
+        "g" => {
+            let (n, cap) = pair()?;
+            Ok(BetSize::Geometric(
+                n.parse().map_err(|_| err())?,
+                cap.parse().map_err(|_| err())?,
+            ))
+        }
+        "a" => Ok(BetSize::AllIn),
+        _ => Err(err()),
+    }
+}
+
+/// Serialises a list of bet sizes as a `;`-separated field (empty when absent).
+fn bet_sizes_to_text(sizes: &[BetSize]) -> String {
+    sizes.iter().map(bet_size_to_text).collect::<Vec<_>>().join(";")
+}
+
+/// Inverse of [`bet_sizes_to_text`].
+fn bet_sizes_from_text(text: &str) -> Result<Vec<BetSize>, String> {
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split(';').map(bet_size_from_text).collect()
+}
+
+/// Serialises one street's `bet/raise` options as `bet|raise`.
+fn bet_size_options_to_text(options: &BetSizeOptions) -> String {
+    format!(
+        "{}|{}",
+        bet_sizes_to_text(&options.bet),
+        bet_sizes_to_text(&options.raise)
+    )
+}
+
+/// Inverse of [`bet_size_options_to_text`].
+fn bet_size_options_from_text(text: &str) -> Result<BetSizeOptions, String> {
+    let (bet, raise) = text.split_once('|').ok_or("missing '|' in bet sizes")?;
+    Ok(BetSizeOptions {
+        bet: bet_sizes_from_text(bet)?,
+        raise: bet_sizes_from_text(raise)?,
+    })
+}
+
+impl PostFlopGame {
+    /// Exports the solved game tree and strategies to the text interchange
+    /// format described above. The returned string carries enough to rebuild
+    /// the tree ([`import_tree_text`]) and, when the game is solved, the
+    /// per-hand strategy and cfvalue arrays at every action node.
+    pub fn export_tree_text(&self) -> String {
+        let cfg = &self.tree_config;
+        let mut out = String::new();
+
+        // Header: version and tree configuration.
+        out.push_str(&format!("VER {VERSION_STR}\n"));
+        out.push_str(&format!("STATE {:?}\n", cfg.initial_state));
+        out.push_str(&format!("POT {}\n", cfg.starting_pot));
+        out.push_str(&format!("STACK {}\n", cfg.effective_stack));
+        out.push_str(&format!("RAKE {} {}\n", cfg.rake_rate, cfg.rake_cap));
+        out.push_str(&format!(
+            "THRESH {} {} {}\n",
+            cfg.add_allin_threshold, cfg.force_allin_threshold, cfg.merging_threshold
+        ));
+        for (label, options) in [
+            ("FLOP", &cfg.flop_bet_sizes),
+            ("TURN", &cfg.turn_bet_sizes),
+            ("RIVER", &cfg.river_bet_sizes),
+        ] {
+            out.push_str(&format!(
+                "BET {label} {} {}\n",
+                bet_size_options_to_text(&options[0]),
+                bet_size_options_to_text(&options[1])
+            ));
+        }
+        for (label, donk) in [
+            ("TURN", &cfg.turn_donk_sizes),
+            ("RIVER", &cfg.river_donk_sizes),
+        ] {
+            if let Some(options) = donk {
+                out.push_str(&format!("DONK {label} {}\n", bet_sizes_to_text(&options.donk)));
+            }
+        }
+
+        // Added/removed lines that shape the tree.
+        for line in &self.added_lines {
+            out.push_str(&format!("ADD {}\n", line_to_text(line)));
+        }
+        for line in &self.removed_lines {
+            out.push_str(&format!("REM {}\n", line_to_text(line)));
+        }
+
+        // Node tree (SGF-style), walked from the root via `children()`.
+        if !self.node_arena.is_empty() {
+            let root = self.node_arena[0].lock();
+            self.write_node_text(&mut out, &root, 0);
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Recursively writes one node and its subtree as `(;PROP[..]..)`.
+    fn write_node_text(&self, out: &mut String, node: &PostFlopNode, depth: usize) {
+        out.push('\n');
+        out.push_str(&"  ".repeat(depth));
+        out.push_str("(;");
+        out.push_str(&format!("PL[{}]", node.player));
+        out.push_str(&format!("PA[{}]", action_to_text(&node.prev_action)));
+        if node.turn != NOT_DEALT {
+            out.push_str(&format!("TU[{}]", node.turn));
+        }
+        if node.river != NOT_DEALT {
+            out.push_str(&format!("RI[{}]", node.river));
+        }
+        out.push_str(&format!("AM[{}]", node.amount));
+
+        if !node.is_terminal() && !node.is_chance() && !node.storage1.is_null() {
+            let strategy =
+                self.read_node_values(node.storage1, node.num_elements, node.scale1, false);
+            out.push_str(&format!("ST[{}]", floats_to_text(&strategy)));
+            if !node.storage2.is_null() {
+                let ev = self.read_node_values(node.storage2, node.num_elements, node.scale2, true);
+                out.push_str(&format!("EV[{}]", floats_to_text(&ev)));
+            }
+        }
+
+        for child in node.children() {
+            self.write_node_text(out, &child.lock(), depth + 1);
+        }
+        out.push(')');
+    }
+
+    /// Reads `num_elements` stored values at `ptr` in this game's active storage
+    /// mode. Thin wrapper over [`read_storage_values`] passing
+    /// `self.is_compression_enabled`.
+    fn read_node_values(&self, ptr: *const u8, num_elements: u32, scale: f32, signed: bool) -> Vec<f32> {
+        read_storage_values(ptr, num_elements, scale, self.is_compression_enabled, signed)
+    }
+}
+
+/// Serialises a line (sequence of actions) as space-separated action tokens.
+fn line_to_text(line: &[Action]) -> String {
+    line.iter().map(action_to_text).collect::<Vec<_>>().join(" ")
+}
+
+/// Inverse of [`line_to_text`].
+fn line_from_text(text: &str) -> Result<Vec<Action>, String> {
+    text.split_whitespace().map(action_from_text).collect()
+}
+
+/// Formats a float array as a compact comma-separated list.
+fn floats_to_text(values: &[f32]) -> String {
+    values.iter().map(|v| format!("{v}")).collect::<Vec<_>>().join(",")
+}
+
+/// Parses a text export back into the [`TreeConfig`] and added/removed lines
+/// needed to rebuild the action tree. The annotated node tree that follows the
+/// header is ignored here: once the tree is rebuilt, frequencies are re-solved
+/// or re-loaded through the usual path.
+pub fn import_tree_text(text: &str) -> Result<ImportedTree, String> {
+    let mut initial_state = None;
+    let mut starting_pot = 0;
+    let mut effective_stack = 0;
+    let mut rake_rate = 0.0;
+    let mut rake_cap = 0.0;
+    let mut add_allin_threshold = 0.0;
+    let mut force_allin_threshold = 0.0;
+    let mut merging_threshold = 0.0;
+    let mut streets: [Option<[BetSizeOptions; 2]>; 3] = [None, None, None];
+    let mut turn_donk_sizes = None;
+    let mut river_donk_sizes = None;
+    let mut added_lines = Vec::new();
+    let mut removed_lines = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('(') {
+            break; // reached the node tree
+        }
+        let (key, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let value = value.trim();
+        match key {
+            "VER" => {}
+            "STATE" => {
+                initial_state = Some(match value {
+                    "Flop" => BoardState::Flop,
+                    "Turn" => BoardState::Turn,
+                    "River" => BoardState::River,
+                    _ => return Err(format!("unknown board state: '{value}'")),
+                });
+            }
+            "POT" => starting_pot = value.parse().map_err(|_| "bad POT")?,
+            "STACK" => effective_stack = value.parse().map_err(|_| "bad STACK")?,
+            "RAKE" => {
+                let (rate, cap) = value.split_once(' ').ok_or("bad RAKE")?;
+                rake_rate = rate.parse().map_err(|_| "bad rake rate")?;
+                rake_cap = cap.parse().map_err(|_| "bad rake cap")?;
+            }
+            "THRESH" => {
+                let mut it = value.split_whitespace();
+                let mut next = || it.next().ok_or("bad THRESH").and_then(|s| s.parse().map_err(|_| "bad THRESH"));
+                add_allin_threshold = next()?;
+                force_allin_threshold = next()?;
+                merging_threshold = next()?;
+            }
+            "BET" => {
+                let (street, rest) = value.split_once(' ').ok_or("bad BET")?;
+                let (oop, ip) = rest.split_once(' ').ok_or("bad BET")?;
+                let options = [
+                    bet_size_options_from_text(oop)?,
+                    bet_size_options_from_text(ip)?,
+                ];
+                let index = match street {
+                    "FLOP" => 0,
+                    "TURN" => 1,
+                    "RIVER" => 2,
+                    _ => return Err(format!("unknown street: '{street}'")),
+                };
+                streets[index] = Some(options);
+            }
+            "DONK" => {
+                let (street, rest) = value.split_once(' ').unwrap_or((value, ""));
+                let options = DonkSizeOptions {
+                    donk: bet_sizes_from_text(rest.trim())?,
+                };
+                match street {
+                    "TURN" => turn_donk_sizes = Some(options),
+                    "RIVER" => river_donk_sizes = Some(options),
+                    _ => return Err(format!("unknown donk street: '{street}'")),
+                }
+            }
+            "ADD" => added_lines.push(line_from_text(value)?),
+            "REM" => removed_lines.push(line_from_text(value)?),
+            _ => return Err(format!("unknown header key: '{key}'")),
+        }
+    }
+
+    let tree_config = TreeConfig {
+        initial_state: initial_state.ok_or("missing STATE")?,
+        starting_pot,
+        effective_stack,
+        rake_rate,
+        rake_cap,
+        flop_bet_sizes: streets[0].clone().ok_or("missing FLOP bet sizes")?,
+        turn_bet_sizes: streets[1].clone().ok_or("missing TURN bet sizes")?,
+        river_bet_sizes: streets[2].clone().ok_or("missing RIVER bet sizes")?,
+        turn_donk_sizes,
+        river_donk_sizes,
+        add_allin_threshold,
+        force_allin_threshold,
+        merging_threshold,
+    };
+
+    Ok(ImportedTree {
+        tree_config,
+        added_lines,
+        removed_lines,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small turn game, solved for a handful of iterations, used as the
+    // round-trip fixture for every on-disk format. `compression` selects the
+    // i16 storage mode (required by the bit-packed encoder).
+    fn solved_game(compression: bool) -> PostFlopGame {
+        let card_config = CardConfig {
+            range: ["AA,KK,QQ".parse().unwrap(), "AA,KK,QQ".parse().unwrap()],
+            flop: [0, 4, 8], // 2c 3c 4c — disjoint from the ranges
+            turn: 12,        // 5c
+            river: NOT_DEALT,
+        };
+        let tree_config = TreeConfig {
+            initial_state: BoardState::Turn,
+            starting_pot: 60,
+            effective_stack: 100,
+            rake_rate: 0.0,
+            rake_cap: 0.0,
+            flop_bet_sizes: Default::default(),
+            turn_bet_sizes: [
+                BetSizeOptions::try_from(("50%", "")).unwrap(),
+                BetSizeOptions::try_from(("50%", "")).unwrap(),
+            ],
+            river_bet_sizes: [
+                BetSizeOptions::try_from(("50%", "")).unwrap(),
+                BetSizeOptions::try_from(("50%", "")).unwrap(),
+            ],
+            turn_donk_sizes: None,
+            river_donk_sizes: None,
+            add_allin_threshold: 0.0,
+            force_allin_threshold: 0.0,
+            merging_threshold: 0.0,
+        };
+
+        let action_tree = ActionTree::new(tree_config).unwrap();
+        let mut game = PostFlopGame::with_config(card_config, action_tree).unwrap();
+        game.allocate_memory(compression);
+        crate::solve(&mut game, 30, 0.0, false);
+        game.cache_normalized_weights();
+        game
+    }
+
+    // Strategy at the root, used to compare a decoded game against the original.
+    fn root_strategy(game: &mut PostFlopGame) -> Vec<f32> {
+        game.back_to_root();
+        game.cache_normalized_weights();
+        game.strategy()
+    }
+
+    fn assert_close(a: &[f32], b: &[f32], tol: f32) {
+        assert_eq!(a.len(), b.len(), "length mismatch");
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() <= tol, "value mismatch: {x} vs {y}");
+        }
+    }
+
+    #[test]
+    fn round_trip_plain() {
+        let mut game = solved_game(false);
+        let expected = root_strategy(&mut game);
+        let bytes = encode_game(&game, false).unwrap();
+        let mut decoded = decode_game(&bytes).unwrap();
+        assert_close(&root_strategy(&mut decoded), &expected, 0.0);
+    }
+
+    #[test]
+    fn round_trip_compressed() {
+        let mut game = solved_game(false);
+        let expected = root_strategy(&mut game);
+        let bytes = encode_game(&game, true).unwrap();
+        let mut decoded = decode_game(&bytes).unwrap();
+        assert_close(&root_strategy(&mut decoded), &expected, 0.0);
+    }
+
+    #[test]
+    fn round_trip_mmap_and_into_owned() {
+        let mut game = solved_game(false);
+        let expected = root_strategy(&mut game);
+
+        let bytes = encode_game_mmap(&game).unwrap();
+        let path = std::env::temp_dir().join("pfs_mmap_round_trip.bin");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let mapped = load_mmap(&path).unwrap();
+        // Materialize an owned copy; the mapping is dropped inside `into_owned`...
+        let mut owned = mapped.into_owned();
+        // ...and the owned copy reproduces the original strategy.
+        assert_close(&root_strategy(&mut owned), &expected, 0.0);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn decode_game_rejects_mmap_layout() {
+        let game = solved_game(false);
+        let bytes = encode_game_mmap(&game).unwrap();
+        assert!(decode_game(&bytes).is_err());
+    }
+
+    #[test]
+    fn round_trip_quantized() {
+        let mut game = solved_game(true);
+        let expected = root_strategy(&mut game);
+        for width in [8, 12] {
+            let bytes = encode_game_quantized(&game, width).unwrap();
+            let mut decoded = decode_game(&bytes).unwrap();
+            // Lossy, but close: tolerate 0.05 per strategy frequency.
+            assert_close(&root_strategy(&mut decoded), &expected, 0.05);
+        }
+    }
+
+    #[test]
+    fn quantized_rejects_f32_mode() {
+        let game = solved_game(false);
+        assert!(encode_game_quantized(&game, 8).is_err());
+    }
+
+    #[test]
+    fn round_trip_text_tree() {
+        let game = solved_game(false);
+        let text = game.export_tree_text();
+        let imported = import_tree_text(&text).unwrap();
+
+        let cfg = &game.tree_config;
+        assert_eq!(imported.tree_config.initial_state, cfg.initial_state);
+        assert_eq!(imported.tree_config.starting_pot, cfg.starting_pot);
+        assert_eq!(imported.tree_config.effective_stack, cfg.effective_stack);
+        assert_eq!(imported.tree_config.turn_bet_sizes, cfg.turn_bet_sizes);
+        assert_eq!(imported.tree_config.river_bet_sizes, cfg.river_bet_sizes);
+
+        // The imported config builds an action tree.
+        assert!(ActionTree::new(imported.tree_config).is_ok());
+    }
+
+    #[test]
+    fn round_trip_text_tree_with_donk_sizes() {
+        let mut game = solved_game(false);
+        game.tree_config.turn_donk_sizes = Some(DonkSizeOptions::try_from("50%").unwrap());
+        game.tree_config.river_donk_sizes = Some(DonkSizeOptions::try_from("50%").unwrap());
+
+        let text = game.export_tree_text();
+        let imported = import_tree_text(&text).unwrap();
+        assert_eq!(imported.tree_config.turn_donk_sizes, game.tree_config.turn_donk_sizes);
+        assert_eq!(imported.tree_config.river_donk_sizes, game.tree_config.river_donk_sizes);
+    }
+
+    #[test]
+    fn import_rejects_empty_bet_size_token() {
+        // Trailing ';' yields an empty token; must error, not panic.
+        assert!(bet_sizes_from_text("p1.5;").is_err());
+    }
+}